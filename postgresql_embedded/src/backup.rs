@@ -0,0 +1,172 @@
+use crate::command::pg_basebackup::PgBaseBackupBuilder;
+use crate::command::pg_dump::PgDumpBuilder;
+use crate::command::pg_restore::PgRestoreBuilder;
+use crate::command::traits::{CommandBuilder, CommandToString};
+use crate::error::{Error, Result};
+use crate::postgresql::PostgreSQL;
+use std::path::{Path, PathBuf};
+use tokio::process::Command;
+
+async fn run(builder: impl CommandBuilder, password: &str) -> Result<()> {
+    let command_string = builder.to_command_string();
+    let command = builder.build();
+    let output = Command::new(command.get_program())
+        .args(command.get_args())
+        .env("PGPASSWORD", password)
+        .output()
+        .await
+        .map_err(Error::Io)?;
+
+    if !output.status.success() {
+        return Err(Error::CommandError(format!(
+            "{command_string} exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+
+    Ok(())
+}
+
+fn dump_builder(
+    program_dir: impl Into<PathBuf>,
+    host: &str,
+    port: u16,
+    username: &str,
+    database_name: &str,
+    file: &Path,
+) -> PgDumpBuilder {
+    PgDumpBuilder::new()
+        .program_dir(program_dir)
+        .format("custom")
+        .file(file)
+        .host(host)
+        .port(port)
+        .username(username)
+        .no_password()
+        .dbname(database_name)
+}
+
+fn restore_builder(
+    program_dir: impl Into<PathBuf>,
+    host: &str,
+    port: u16,
+    username: &str,
+    database_name: &str,
+    file: &Path,
+) -> PgRestoreBuilder {
+    PgRestoreBuilder::new()
+        .program_dir(program_dir)
+        .clean()
+        .if_exists()
+        .host(host)
+        .port(port)
+        .username(username)
+        .no_password()
+        .dbname(database_name)
+        .file(file)
+}
+
+fn base_backup_builder(
+    program_dir: impl Into<PathBuf>,
+    host: &str,
+    port: u16,
+    username: &str,
+    destination_dir: &Path,
+) -> PgBaseBackupBuilder {
+    PgBaseBackupBuilder::new()
+        .program_dir(program_dir)
+        .pgdata(destination_dir)
+        .host(host)
+        .port(port)
+        .username(username)
+        .no_password()
+}
+
+impl PostgreSQL {
+    /// Dump `database_name` to `file` using the managed instance's host, port and
+    /// superuser credentials (including its password, set via `PGPASSWORD`). The
+    /// custom archive format (`-Fc`) is used by default so the resulting file can be
+    /// restored with [`PostgreSQL::restore_database`].
+    pub async fn dump_database(&self, database_name: &str, file: impl AsRef<Path>) -> Result<()> {
+        let settings = self.settings();
+        let builder = dump_builder(
+            &settings.binary_dir,
+            &settings.host,
+            settings.port,
+            &settings.username,
+            database_name,
+            file.as_ref(),
+        );
+
+        run(builder, &settings.password).await
+    }
+
+    /// Restore `database_name` from `file`, previously created with
+    /// [`PostgreSQL::dump_database`], using the managed instance's host, port and
+    /// superuser credentials (including its password, set via `PGPASSWORD`).
+    pub async fn restore_database(&self, database_name: &str, file: impl AsRef<Path>) -> Result<()> {
+        let settings = self.settings();
+        let builder = restore_builder(
+            &settings.binary_dir,
+            &settings.host,
+            settings.port,
+            &settings.username,
+            database_name,
+            file.as_ref(),
+        );
+
+        run(builder, &settings.password).await
+    }
+
+    /// Take a base backup of the running instance into `destination_dir`, using the
+    /// managed instance's host, port and superuser credentials (including its
+    /// password, set via `PGPASSWORD`).
+    pub async fn base_backup(&self, destination_dir: impl AsRef<Path>) -> Result<()> {
+        let settings = self.settings();
+        let builder = base_backup_builder(
+            &settings.binary_dir,
+            &settings.host,
+            settings.port,
+            &settings.username,
+            destination_dir.as_ref(),
+        );
+
+        run(builder, &settings.password).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dump_builder() {
+        let command = dump_builder(".", "localhost", 5432, "postgres", "test", Path::new("dump.bin")).build();
+
+        assert_eq!(
+            r#""./pg_dump" "--format" "custom" "--file" "dump.bin" "--host" "localhost" "--port" "5432" "--username" "postgres" "--no-password" "test""#,
+            command.to_command_string()
+        );
+    }
+
+    #[test]
+    fn test_restore_builder() {
+        let command = restore_builder(".", "localhost", 5432, "postgres", "test", Path::new("dump.bin")).build();
+
+        assert_eq!(
+            r#""./pg_restore" "--dbname" "test" "--clean" "--if-exists" "--host" "localhost" "--port" "5432" "--username" "postgres" "--no-password" "dump.bin""#,
+            command.to_command_string()
+        );
+    }
+
+    #[test]
+    fn test_base_backup_builder() {
+        let command = base_backup_builder(".", "localhost", 5432, "postgres", Path::new("backup")).build();
+
+        assert_eq!(
+            r#""./pg_basebackup" "--pgdata" "backup" "--no-password" "--host" "localhost" "--port" "5432" "--username" "postgres""#,
+            command.to_command_string()
+        );
+    }
+}
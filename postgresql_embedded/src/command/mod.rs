@@ -0,0 +1,8 @@
+//! Command-line builders for the `postgres` contrib binaries the crate shells out to.
+
+pub mod pg_basebackup;
+pub mod pg_dump;
+pub mod pg_restore;
+pub mod pg_test_timing;
+pub mod traits;
+pub mod vacuumdb;
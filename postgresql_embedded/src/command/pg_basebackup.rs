@@ -0,0 +1,234 @@
+use crate::command::traits::CommandBuilder;
+use std::convert::AsRef;
+use std::ffi::{OsStr, OsString};
+use std::path::PathBuf;
+
+/// pg_basebackup takes a base backup of a running PostgreSQL server.
+#[derive(Clone, Debug, Default)]
+pub struct PgBaseBackupBuilder {
+    program_dir: Option<PathBuf>,
+    pgdata: Option<PathBuf>,
+    format: Option<OsString>,
+    checkpoint: Option<OsString>,
+    compress: Option<OsString>,
+    jobs: Option<u32>,
+    wal_method: Option<OsString>,
+    verbose: bool,
+    progress: bool,
+    no_password: bool,
+    password: bool,
+    host: Option<OsString>,
+    port: Option<u16>,
+    username: Option<OsString>,
+}
+
+impl PgBaseBackupBuilder {
+    /// Create a new [`PgBaseBackupBuilder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Location of the program binary
+    pub fn program_dir<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.program_dir = Some(path.into());
+        self
+    }
+
+    /// receive base backup into this directory
+    pub fn pgdata<P: Into<PathBuf>>(mut self, pgdata: P) -> Self {
+        self.pgdata = Some(pgdata.into());
+        self
+    }
+
+    /// output format (plain, tar)
+    pub fn format<S: AsRef<OsStr>>(mut self, format: S) -> Self {
+        self.format = Some(format.as_ref().to_os_string());
+        self
+    }
+
+    /// set fast or spread checkpointing
+    pub fn checkpoint<S: AsRef<OsStr>>(mut self, checkpoint: S) -> Self {
+        self.checkpoint = Some(checkpoint.as_ref().to_os_string());
+        self
+    }
+
+    /// compress tar output, optionally with a method and level
+    pub fn compress<S: AsRef<OsStr>>(mut self, compress: S) -> Self {
+        self.compress = Some(compress.as_ref().to_os_string());
+        self
+    }
+
+    /// use this many parallel jobs to backup
+    pub fn jobs(mut self, jobs: u32) -> Self {
+        self.jobs = Some(jobs);
+        self
+    }
+
+    /// include required WAL files using this method
+    pub fn wal_method<S: AsRef<OsStr>>(mut self, wal_method: S) -> Self {
+        self.wal_method = Some(wal_method.as_ref().to_os_string());
+        self
+    }
+
+    /// write a lot of output
+    pub fn verbose(mut self) -> Self {
+        self.verbose = true;
+        self
+    }
+
+    /// show progress information
+    pub fn progress(mut self) -> Self {
+        self.progress = true;
+        self
+    }
+
+    /// never prompt for password
+    pub fn no_password(mut self) -> Self {
+        self.no_password = true;
+        self
+    }
+
+    /// force password prompt
+    pub fn password(mut self) -> Self {
+        self.password = true;
+        self
+    }
+
+    /// database server host or socket directory
+    pub fn host<S: AsRef<OsStr>>(mut self, host: S) -> Self {
+        self.host = Some(host.as_ref().to_os_string());
+        self
+    }
+
+    /// database server port
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// user name to connect as
+    pub fn username<S: AsRef<OsStr>>(mut self, username: S) -> Self {
+        self.username = Some(username.as_ref().to_os_string());
+        self
+    }
+}
+
+impl CommandBuilder for PgBaseBackupBuilder {
+    /// Get the program name
+    fn get_program(&self) -> &'static OsStr {
+        "pg_basebackup".as_ref()
+    }
+
+    /// Location of the program binary
+    fn get_program_dir(&self) -> &Option<PathBuf> {
+        &self.program_dir
+    }
+
+    /// Get the arguments for the command
+    fn get_args(&self) -> Vec<OsString> {
+        let mut args: Vec<OsString> = Vec::new();
+
+        if let Some(pgdata) = &self.pgdata {
+            args.push("--pgdata".into());
+            args.push(pgdata.into());
+        }
+
+        if let Some(format) = &self.format {
+            args.push("--format".into());
+            args.push(format.into());
+        }
+
+        if let Some(checkpoint) = &self.checkpoint {
+            args.push("--checkpoint".into());
+            args.push(checkpoint.into());
+        }
+
+        if let Some(compress) = &self.compress {
+            args.push("--compress".into());
+            args.push(compress.into());
+        }
+
+        if let Some(jobs) = &self.jobs {
+            args.push("--jobs".into());
+            args.push(jobs.to_string().into());
+        }
+
+        if let Some(wal_method) = &self.wal_method {
+            args.push("--wal-method".into());
+            args.push(wal_method.into());
+        }
+
+        if self.verbose {
+            args.push("--verbose".into());
+        }
+
+        if self.progress {
+            args.push("--progress".into());
+        }
+
+        if self.no_password {
+            args.push("--no-password".into());
+        }
+
+        if self.password {
+            args.push("--password".into());
+        }
+
+        if let Some(host) = &self.host {
+            args.push("--host".into());
+            args.push(host.into());
+        }
+
+        if let Some(port) = &self.port {
+            args.push("--port".into());
+            args.push(port.to_string().into());
+        }
+
+        if let Some(username) = &self.username {
+            args.push("--username".into());
+            args.push(username.into());
+        }
+
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::traits::CommandToString;
+
+    #[test]
+    fn test_builder_new() {
+        let command = PgBaseBackupBuilder::new().program_dir(".").build();
+
+        assert_eq!(
+            PathBuf::from(".").join("pg_basebackup"),
+            PathBuf::from(command.to_command_string().replace("\"", ""))
+        );
+    }
+
+    #[test]
+    fn test_builder() {
+        let command = PgBaseBackupBuilder::new()
+            .pgdata("backup_dir")
+            .format("tar")
+            .checkpoint("fast")
+            .compress("gzip:9")
+            .jobs(2)
+            .wal_method("stream")
+            .verbose()
+            .progress()
+            .no_password()
+            .password()
+            .host("localhost")
+            .port(5432)
+            .username("username")
+            .build();
+
+        assert_eq!(
+            r#""pg_basebackup" "--pgdata" "backup_dir" "--format" "tar" "--checkpoint" "fast" "--compress" "gzip:9" "--jobs" "2" "--wal-method" "stream" "--verbose" "--progress" "--no-password" "--password" "--host" "localhost" "--port" "5432" "--username" "username""#,
+            command.to_command_string()
+        );
+    }
+}
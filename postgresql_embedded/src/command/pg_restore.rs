@@ -0,0 +1,330 @@
+use crate::command::traits::CommandBuilder;
+use std::convert::AsRef;
+use std::ffi::{OsStr, OsString};
+use std::path::PathBuf;
+
+/// pg_restore restores a PostgreSQL database from an archive created by pg_dump.
+#[derive(Clone, Debug, Default)]
+pub struct PgRestoreBuilder {
+    program_dir: Option<PathBuf>,
+    dbname: Option<OsString>,
+    file: Option<PathBuf>,
+    format: Option<OsString>,
+    jobs: Option<u32>,
+    verbose: bool,
+    no_owner: bool,
+    no_privileges: bool,
+    schema: Option<OsString>,
+    exclude_schema: Option<OsString>,
+    table: Option<OsString>,
+    schema_only: bool,
+    data_only: bool,
+    clean: bool,
+    create: bool,
+    if_exists: bool,
+    single_transaction: bool,
+    host: Option<OsString>,
+    port: Option<u16>,
+    username: Option<OsString>,
+    no_password: bool,
+    password: bool,
+}
+
+impl PgRestoreBuilder {
+    /// Create a new [`PgRestoreBuilder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Location of the program binary
+    pub fn program_dir<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.program_dir = Some(path.into());
+        self
+    }
+
+    /// database to restore into
+    pub fn dbname<S: AsRef<OsStr>>(mut self, dbname: S) -> Self {
+        self.dbname = Some(dbname.as_ref().to_os_string());
+        self
+    }
+
+    /// input file or directory name
+    pub fn file<P: Into<PathBuf>>(mut self, file: P) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// backup file format (should be automatic, but can override)
+    pub fn format<S: AsRef<OsStr>>(mut self, format: S) -> Self {
+        self.format = Some(format.as_ref().to_os_string());
+        self
+    }
+
+    /// use this many parallel jobs to restore
+    pub fn jobs(mut self, jobs: u32) -> Self {
+        self.jobs = Some(jobs);
+        self
+    }
+
+    /// write a lot of output
+    pub fn verbose(mut self) -> Self {
+        self.verbose = true;
+        self
+    }
+
+    /// skip restoration of object ownership
+    pub fn no_owner(mut self) -> Self {
+        self.no_owner = true;
+        self
+    }
+
+    /// do not restore privileges (grant/revoke)
+    pub fn no_privileges(mut self) -> Self {
+        self.no_privileges = true;
+        self
+    }
+
+    /// restore the specified schema(s) only
+    pub fn schema<S: AsRef<OsStr>>(mut self, schema: S) -> Self {
+        self.schema = Some(schema.as_ref().to_os_string());
+        self
+    }
+
+    /// do not restore the specified schema(s)
+    pub fn exclude_schema<S: AsRef<OsStr>>(mut self, exclude_schema: S) -> Self {
+        self.exclude_schema = Some(exclude_schema.as_ref().to_os_string());
+        self
+    }
+
+    /// restore the specified table(s) only
+    pub fn table<S: AsRef<OsStr>>(mut self, table: S) -> Self {
+        self.table = Some(table.as_ref().to_os_string());
+        self
+    }
+
+    /// restore only the schema, no data
+    pub fn schema_only(mut self) -> Self {
+        self.schema_only = true;
+        self
+    }
+
+    /// restore only the data, not the schema
+    pub fn data_only(mut self) -> Self {
+        self.data_only = true;
+        self
+    }
+
+    /// clean (drop) database objects before recreating
+    pub fn clean(mut self) -> Self {
+        self.clean = true;
+        self
+    }
+
+    /// create the target database
+    pub fn create(mut self) -> Self {
+        self.create = true;
+        self
+    }
+
+    /// use IF EXISTS when dropping objects
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// restore as a single transaction
+    pub fn single_transaction(mut self) -> Self {
+        self.single_transaction = true;
+        self
+    }
+
+    /// database server host or socket directory
+    pub fn host<S: AsRef<OsStr>>(mut self, host: S) -> Self {
+        self.host = Some(host.as_ref().to_os_string());
+        self
+    }
+
+    /// database server port
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// user name to connect as
+    pub fn username<S: AsRef<OsStr>>(mut self, username: S) -> Self {
+        self.username = Some(username.as_ref().to_os_string());
+        self
+    }
+
+    /// never prompt for password
+    pub fn no_password(mut self) -> Self {
+        self.no_password = true;
+        self
+    }
+
+    /// force password prompt
+    pub fn password(mut self) -> Self {
+        self.password = true;
+        self
+    }
+}
+
+impl CommandBuilder for PgRestoreBuilder {
+    /// Get the program name
+    fn get_program(&self) -> &'static OsStr {
+        "pg_restore".as_ref()
+    }
+
+    /// Location of the program binary
+    fn get_program_dir(&self) -> &Option<PathBuf> {
+        &self.program_dir
+    }
+
+    /// Get the arguments for the command
+    fn get_args(&self) -> Vec<OsString> {
+        let mut args: Vec<OsString> = Vec::new();
+
+        if let Some(format) = &self.format {
+            args.push("--format".into());
+            args.push(format.into());
+        }
+
+        if let Some(dbname) = &self.dbname {
+            args.push("--dbname".into());
+            args.push(dbname.into());
+        }
+
+        if let Some(jobs) = &self.jobs {
+            args.push("--jobs".into());
+            args.push(jobs.to_string().into());
+        }
+
+        if self.verbose {
+            args.push("--verbose".into());
+        }
+
+        if self.no_owner {
+            args.push("--no-owner".into());
+        }
+
+        if self.no_privileges {
+            args.push("--no-privileges".into());
+        }
+
+        if let Some(schema) = &self.schema {
+            args.push("--schema".into());
+            args.push(schema.into());
+        }
+
+        if let Some(exclude_schema) = &self.exclude_schema {
+            args.push("--exclude-schema".into());
+            args.push(exclude_schema.into());
+        }
+
+        if let Some(table) = &self.table {
+            args.push("--table".into());
+            args.push(table.into());
+        }
+
+        if self.schema_only {
+            args.push("--schema-only".into());
+        }
+
+        if self.data_only {
+            args.push("--data-only".into());
+        }
+
+        if self.clean {
+            args.push("--clean".into());
+        }
+
+        if self.create {
+            args.push("--create".into());
+        }
+
+        if self.if_exists {
+            args.push("--if-exists".into());
+        }
+
+        if self.single_transaction {
+            args.push("--single-transaction".into());
+        }
+
+        if let Some(host) = &self.host {
+            args.push("--host".into());
+            args.push(host.into());
+        }
+
+        if let Some(port) = &self.port {
+            args.push("--port".into());
+            args.push(port.to_string().into());
+        }
+
+        if let Some(username) = &self.username {
+            args.push("--username".into());
+            args.push(username.into());
+        }
+
+        if self.no_password {
+            args.push("--no-password".into());
+        }
+
+        if self.password {
+            args.push("--password".into());
+        }
+
+        if let Some(file) = &self.file {
+            args.push(file.into());
+        }
+
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::traits::CommandToString;
+
+    #[test]
+    fn test_builder_new() {
+        let command = PgRestoreBuilder::new().program_dir(".").build();
+
+        assert_eq!(
+            PathBuf::from(".").join("pg_restore"),
+            PathBuf::from(command.to_command_string().replace("\"", ""))
+        );
+    }
+
+    #[test]
+    fn test_builder() {
+        let command = PgRestoreBuilder::new()
+            .format("custom")
+            .dbname("dbname")
+            .jobs(4)
+            .verbose()
+            .no_owner()
+            .no_privileges()
+            .schema("schema")
+            .exclude_schema("exclude_schema")
+            .table("table")
+            .schema_only()
+            .data_only()
+            .clean()
+            .create()
+            .if_exists()
+            .single_transaction()
+            .host("localhost")
+            .port(5432)
+            .username("username")
+            .no_password()
+            .password()
+            .file("dump.bin")
+            .build();
+
+        assert_eq!(
+            r#""pg_restore" "--format" "custom" "--dbname" "dbname" "--jobs" "4" "--verbose" "--no-owner" "--no-privileges" "--schema" "schema" "--exclude-schema" "exclude_schema" "--table" "table" "--schema-only" "--data-only" "--clean" "--create" "--if-exists" "--single-transaction" "--host" "localhost" "--port" "5432" "--username" "username" "--no-password" "--password" "dump.bin""#,
+            command.to_command_string()
+        );
+    }
+}
@@ -1,7 +1,9 @@
-use crate::command::traits::CommandBuilder;
+use crate::command::traits::{CommandBuilder, CommandToString};
+use crate::error::{Error, Result};
 use std::convert::AsRef;
 use std::ffi::{OsStr, OsString};
 use std::path::PathBuf;
+use tokio::process::Command;
 
 /// pg_test_timing tests the timing of a PostgreSQL instance.
 #[derive(Clone, Debug, Default)]
@@ -53,6 +55,107 @@ impl CommandBuilder for PgTestTimingBuilder {
     }
 }
 
+/// A single row of the `pg_test_timing` histogram.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimingBucket {
+    /// Upper bound of the bucket, in microseconds.
+    pub le_microseconds: u64,
+    /// Percentage of total measurements falling into this bucket.
+    pub percent: f64,
+    /// Number of measurements falling into this bucket.
+    pub count: u64,
+}
+
+/// Parsed result of running `pg_test_timing`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimingReport {
+    /// The requested test duration, in seconds.
+    pub test_duration_seconds: f64,
+    /// "Per loop time including overhead", in nanoseconds.
+    pub per_loop_time_ns: f64,
+    /// The parsed histogram, in the order reported by `pg_test_timing`.
+    pub histogram: Vec<TimingBucket>,
+}
+
+impl PgTestTimingBuilder {
+    /// Run `pg_test_timing` and parse its output into a [`TimingReport`].
+    pub async fn run(&self) -> Result<TimingReport> {
+        let command = self.build();
+        let output = Command::new(command.get_program())
+            .args(command.get_args())
+            .output()
+            .await
+            .map_err(Error::Io)?;
+
+        if !output.status.success() {
+            return Err(Error::CommandError(format!(
+                "{} exited with {}: {}",
+                command.to_command_string(),
+                output.status,
+                String::from_utf8_lossy(&output.stderr)
+            )));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        parse_timing_report(&stdout)
+    }
+}
+
+/// Parse the textual output of `pg_test_timing` into a [`TimingReport`].
+///
+/// `pg_test_timing`'s histogram column layout has changed across PostgreSQL
+/// versions, so rows are parsed as whitespace-delimited numeric columns
+/// (bucket upper bound, percentage, count) rather than fixed offsets.
+fn parse_timing_report(output: &str) -> Result<TimingReport> {
+    let mut test_duration_seconds = None;
+    let mut per_loop_time_ns = None;
+    let mut histogram = Vec::new();
+
+    for line in output.lines() {
+        let line = line.trim();
+
+        if let Some(seconds) = line
+            .strip_prefix("Testing timing overhead for ")
+            .and_then(|rest| rest.strip_suffix(" seconds."))
+        {
+            test_duration_seconds = seconds.trim().parse::<f64>().ok();
+            continue;
+        }
+
+        if let Some(value) = line.strip_prefix("Per loop time including overhead:") {
+            let value = value.trim().trim_end_matches("ns").trim();
+            per_loop_time_ns = value.parse::<f64>().ok();
+            continue;
+        }
+
+        let columns: Vec<&str> = line.split_whitespace().collect();
+        if columns.len() == 3 {
+            if let (Ok(le_microseconds), Ok(percent), Ok(count)) = (
+                columns[0].parse::<u64>(),
+                columns[1].parse::<f64>(),
+                columns[2].parse::<u64>(),
+            ) {
+                histogram.push(TimingBucket {
+                    le_microseconds,
+                    percent,
+                    count,
+                });
+            }
+        }
+    }
+
+    let test_duration_seconds = test_duration_seconds
+        .ok_or_else(|| Error::CommandError("unable to parse test duration".to_string()))?;
+    let per_loop_time_ns = per_loop_time_ns
+        .ok_or_else(|| Error::CommandError("unable to parse per loop time".to_string()))?;
+
+    Ok(TimingReport {
+        test_duration_seconds,
+        per_loop_time_ns,
+        histogram,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,4 +177,37 @@ mod tests {
 
         assert_eq!(r#""pg_test_timing" "-d" "10""#, command.to_command_string());
     }
+
+    #[test]
+    fn test_parse_timing_report() {
+        let output = "Testing timing overhead for 3 seconds.\n\
+            Per loop time including overhead: 23.88 ns\n\
+            Histogram of timing durations:\n\
+            \x20 < us   % of total      count\n\
+            \x20    1     95.40691   11988573\n\
+            \x20    2      4.20634     528537\n\
+            \x20    4      0.32652      41033\n\
+            \x20   16      0.00215        270\n";
+
+        let report = parse_timing_report(output).expect("valid report");
+
+        assert_eq!(3.0, report.test_duration_seconds);
+        assert_eq!(23.88, report.per_loop_time_ns);
+        assert_eq!(
+            vec![
+                TimingBucket { le_microseconds: 1, percent: 95.40691, count: 11_988_573 },
+                TimingBucket { le_microseconds: 2, percent: 4.20634, count: 528_537 },
+                TimingBucket { le_microseconds: 4, percent: 0.32652, count: 41_033 },
+                TimingBucket { le_microseconds: 16, percent: 0.00215, count: 270 },
+            ],
+            report.histogram
+        );
+    }
+
+    #[test]
+    fn test_parse_timing_report_missing_duration() {
+        let output = "garbage output";
+
+        assert!(parse_timing_report(output).is_err());
+    }
 }
@@ -0,0 +1,49 @@
+use std::ffi::OsStr;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Shared behavior for builders that construct a `postgres` contrib command.
+pub trait CommandBuilder {
+    /// The name of the program to execute.
+    fn get_program(&self) -> &'static OsStr;
+
+    /// The directory containing the program binary, if set.
+    fn get_program_dir(&self) -> &Option<PathBuf>;
+
+    /// The arguments to pass to the program.
+    fn get_args(&self) -> Vec<std::ffi::OsString>;
+
+    /// Build a [`Command`] ready to be spawned.
+    fn build(&self) -> Command {
+        let program: PathBuf = match self.get_program_dir() {
+            Some(dir) => dir.join(self.get_program()),
+            None => PathBuf::from(self.get_program()),
+        };
+
+        let mut command = Command::new(program);
+        command.args(self.get_args());
+        command
+    }
+}
+
+/// Renders a [`Command`] as a shell-quoted string, for logging and tests.
+pub trait CommandToString {
+    /// Render this command as a shell-quoted string.
+    fn to_command_string(&self) -> String;
+}
+
+impl CommandToString for Command {
+    fn to_command_string(&self) -> String {
+        let mut parts = vec![format!("{:?}", self.get_program())];
+        for arg in self.get_args() {
+            parts.push(format!("{:?}", arg));
+        }
+        parts.join(" ")
+    }
+}
+
+impl<T: CommandBuilder> CommandToString for T {
+    fn to_command_string(&self) -> String {
+        self.build().to_command_string()
+    }
+}
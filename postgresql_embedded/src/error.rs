@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+/// Result type for this crate, defaulting to [`Error`].
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+/// Errors returned by this crate.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// A spawned `postgres`/contrib command exited with a non-zero status, or its
+    /// output could not be parsed.
+    #[error("{0}")]
+    CommandError(String),
+
+    /// An I/O error occurred while spawning or communicating with a command, or
+    /// while performing a readiness check.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    /// An operation did not complete within its allotted time.
+    #[error("{0}")]
+    Timeout(String),
+
+    /// A connection-pool error, from [`PostgreSQL::connect_pool`](crate::PostgreSQL::connect_pool).
+    #[cfg(feature = "pool")]
+    #[error(transparent)]
+    Pool(#[from] sqlx::Error),
+}
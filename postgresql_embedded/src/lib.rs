@@ -0,0 +1,19 @@
+//! An embedded PostgreSQL server, for tests and local development.
+
+mod backup;
+pub mod command;
+pub mod error;
+pub mod maintenance;
+#[cfg(feature = "pool")]
+pub mod pool;
+mod postgresql;
+pub mod readiness;
+pub mod settings;
+
+pub use error::{Error, Result};
+pub use maintenance::{MaintenanceRun, MaintenanceScheduleOptions, MaintenanceScheduler};
+pub use postgresql::PostgreSQL;
+#[cfg(feature = "pool")]
+pub use pool::PoolOptions;
+pub use readiness::WaitUntilReady;
+pub use settings::Settings;
@@ -0,0 +1,224 @@
+use crate::command::traits::{CommandBuilder, CommandToString};
+use crate::command::vacuumdb::VacuumDbBuilder;
+use crate::error::Error;
+use crate::postgresql::PostgreSQL;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::process::Command;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+
+/// The outcome of a single scheduled maintenance run.
+#[derive(Clone, Debug)]
+pub struct MaintenanceRun {
+    /// The `vacuumdb` command that was executed.
+    pub command: String,
+    /// `Ok(())` if `vacuumdb` exited successfully, otherwise the error it failed with.
+    pub result: Result<(), Arc<Error>>,
+}
+
+/// Configuration for a [`MaintenanceScheduler`].
+#[derive(Clone, Debug)]
+pub struct MaintenanceScheduleOptions {
+    /// How often to run `vacuumdb`.
+    pub interval: Duration,
+    /// Also update optimizer statistics (`--analyze`).
+    pub analyze: bool,
+    /// Update statistics in stages, for faster results on large databases (`--analyze-in-stages`).
+    pub analyze_in_stages: bool,
+    /// Skip relations that cannot be immediately locked (`--skip-locked`).
+    pub skip_locked: bool,
+    /// Restrict vacuuming to this schema, if set.
+    pub schema: Option<String>,
+    /// Restrict vacuuming to this table, if set.
+    pub table: Option<String>,
+}
+
+impl Default for MaintenanceScheduleOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(300),
+            analyze: true,
+            analyze_in_stages: false,
+            skip_locked: true,
+            schema: None,
+            table: None,
+        }
+    }
+}
+
+/// Runs `vacuumdb` against a managed [`PostgreSQL`] instance on a fixed interval, for
+/// long-running integration test harnesses and local dev servers that accumulate dead
+/// tuples. Each run's outcome is sent over a channel so callers can monitor it; the
+/// scheduler stops once the [`PostgreSQL`] instance it was started for is stopped, or
+/// when [`MaintenanceScheduler::shutdown`] is called, letting an in-flight vacuum
+/// finish before exiting.
+pub struct MaintenanceScheduler {
+    handle: Option<JoinHandle<()>>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl MaintenanceScheduler {
+    /// Spawn a background task that runs `vacuumdb` on `options.interval`, reporting
+    /// each run's outcome on the returned receiver.
+    pub fn start(
+        postgresql: Arc<PostgreSQL>,
+        options: MaintenanceScheduleOptions,
+    ) -> (Self, mpsc::UnboundedReceiver<MaintenanceRun>) {
+        let (shutdown_tx, mut shutdown_rx) = oneshot::channel();
+        let (runs_tx, runs_rx) = mpsc::unbounded_channel();
+
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(options.interval);
+            interval.tick().await;
+
+            loop {
+                tokio::select! {
+                    biased;
+                    _ = &mut shutdown_rx => break,
+                    _ = interval.tick() => {
+                        let run = run_vacuum(&postgresql, &options).await;
+                        let instance_stopped = is_connection_failure(&run.result);
+                        if runs_tx.send(run).is_err() || instance_stopped {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+
+        (
+            Self {
+                handle: Some(handle),
+                shutdown: Some(shutdown_tx),
+            },
+            runs_rx,
+        )
+    }
+
+    /// Signal the scheduler to stop after any in-flight run completes, and wait for
+    /// its background task to exit.
+    pub async fn shutdown(mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.await;
+        }
+    }
+}
+
+impl Drop for MaintenanceScheduler {
+    /// Abort the background task if the scheduler is dropped without calling
+    /// [`MaintenanceScheduler::shutdown`], so a forgotten or panicked-past scheduler
+    /// doesn't keep vacuuming (and holding its `Arc<PostgreSQL>` alive) forever.
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Returns `true` if `result` failed because the server is no longer accepting
+/// connections, which we take as a signal that the [`PostgreSQL`] instance this
+/// scheduler was started for has been stopped. Other I/O errors (e.g. the
+/// `vacuumdb` binary is missing, or a permission error) are not connection
+/// failures and should not stop the scheduler.
+fn is_connection_failure(result: &Result<(), Arc<Error>>) -> bool {
+    match result {
+        Err(error) => match error.as_ref() {
+            Error::CommandError(message) => message.contains("could not connect to server"),
+            Error::Io(error) => crate::readiness::is_transient(error),
+            _ => false,
+        },
+        Ok(()) => false,
+    }
+}
+
+async fn run_vacuum(postgresql: &PostgreSQL, options: &MaintenanceScheduleOptions) -> MaintenanceRun {
+    let settings = postgresql.settings();
+    let mut builder = VacuumDbBuilder::new()
+        .program_dir(&settings.binary_dir)
+        .host(&settings.host)
+        .port(settings.port)
+        .username(&settings.username)
+        .no_password()
+        .all();
+
+    if options.analyze {
+        builder = builder.analyze();
+    }
+    if options.analyze_in_stages {
+        builder = builder.analyze_in_stages();
+    }
+    if options.skip_locked {
+        builder = builder.skip_locked();
+    }
+    if let Some(schema) = &options.schema {
+        builder = builder.schema(schema);
+    }
+    if let Some(table) = &options.table {
+        builder = builder.table(table);
+    }
+
+    let command_string = builder.to_command_string();
+    let command = builder.build();
+    let result = Command::new(command.get_program())
+        .args(command.get_args())
+        .env("PGPASSWORD", &settings.password)
+        .output()
+        .await
+        .map_err(Error::Io)
+        .and_then(|output| {
+            if output.status.success() {
+                Ok(())
+            } else {
+                Err(Error::CommandError(format!(
+                    "{command_string} exited with {}: {}",
+                    output.status,
+                    String::from_utf8_lossy(&output.stderr)
+                )))
+            }
+        })
+        .map_err(Arc::new);
+
+    MaintenanceRun {
+        command: command_string,
+        result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_options() {
+        let options = MaintenanceScheduleOptions::default();
+
+        assert_eq!(Duration::from_secs(300), options.interval);
+        assert!(options.analyze);
+        assert!(!options.analyze_in_stages);
+        assert!(options.skip_locked);
+        assert_eq!(None, options.schema);
+        assert_eq!(None, options.table);
+    }
+
+    #[test]
+    fn test_is_connection_failure() {
+        assert!(is_connection_failure(&Err(Arc::new(Error::CommandError(
+            "vacuumdb: error: connection to server failed: could not connect to server: Connection refused"
+                .to_string()
+        )))));
+        assert!(is_connection_failure(&Err(Arc::new(Error::Io(std::io::Error::from(
+            std::io::ErrorKind::ConnectionRefused
+        ))))));
+        assert!(!is_connection_failure(&Err(Arc::new(Error::Io(std::io::Error::from(
+            std::io::ErrorKind::NotFound
+        ))))));
+        assert!(!is_connection_failure(&Err(Arc::new(Error::CommandError(
+            "vacuumdb: error: permission denied".to_string()
+        )))));
+        assert!(!is_connection_failure(&Ok(())));
+    }
+}
@@ -0,0 +1,151 @@
+//! Connection pool factory, enabled by the `pool` cargo feature.
+
+use crate::error::{Error, Result};
+use crate::postgresql::PostgreSQL;
+use crate::readiness::WaitUntilReady;
+use crate::settings::Settings;
+use sqlx::postgres::{PgPool, PgPoolOptions};
+use std::time::{Duration, Instant};
+
+/// Tuning options for pools built with [`Settings::pool_options`] /
+/// [`PostgreSQL::connect_pool`], mirroring `sqlx::postgres::PgPoolOptions`.
+#[derive(Clone, Debug)]
+pub struct PoolOptions {
+    pub max_connections: u32,
+    pub min_connections: u32,
+    pub acquire_timeout: Duration,
+    pub idle_timeout: Option<Duration>,
+    pub test_before_acquire: bool,
+}
+
+impl Default for PoolOptions {
+    fn default() -> Self {
+        Self {
+            max_connections: 10,
+            min_connections: 0,
+            acquire_timeout: Duration::from_secs(30),
+            idle_timeout: Some(Duration::from_secs(600)),
+            test_before_acquire: true,
+        }
+    }
+}
+
+impl PoolOptions {
+    /// Create a new [`PoolOptions`] with the default tuning.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of connections the pool will maintain.
+    pub fn max_connections(mut self, max_connections: u32) -> Self {
+        self.max_connections = max_connections;
+        self
+    }
+
+    /// Set the minimum number of idle connections the pool will maintain.
+    pub fn min_connections(mut self, min_connections: u32) -> Self {
+        self.min_connections = min_connections;
+        self
+    }
+
+    /// Set the maximum time to wait when acquiring a connection from the pool.
+    pub fn acquire_timeout(mut self, acquire_timeout: Duration) -> Self {
+        self.acquire_timeout = acquire_timeout;
+        self
+    }
+
+    /// Set the maximum idle time for a connection before it is closed.
+    pub fn idle_timeout(mut self, idle_timeout: Option<Duration>) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Set whether a connection is pinged before being handed out.
+    pub fn test_before_acquire(mut self, test_before_acquire: bool) -> Self {
+        self.test_before_acquire = test_before_acquire;
+        self
+    }
+}
+
+impl Settings {
+    /// Build a [`PgPoolOptions`] tuned with `options`.
+    ///
+    /// `PgPoolOptions` only carries pool-sizing knobs (max/min connections, timeouts);
+    /// it has no connection target. The actual host/port/username/password come from
+    /// [`Settings::url`] when the pool is connected, via [`PostgreSQL::connect_pool`].
+    pub fn pool_options(&self, options: &PoolOptions) -> PgPoolOptions {
+        let mut pool_options = PgPoolOptions::new()
+            .max_connections(options.max_connections)
+            .min_connections(options.min_connections)
+            .acquire_timeout(options.acquire_timeout)
+            .test_before_acquire(options.test_before_acquire);
+
+        pool_options = match options.idle_timeout {
+            Some(idle_timeout) => pool_options.idle_timeout(Some(idle_timeout)),
+            None => pool_options.idle_timeout(None),
+        };
+
+        pool_options
+    }
+}
+
+impl PostgreSQL {
+    /// Build a connection pool for `database_name` using the default [`PoolOptions`],
+    /// waiting for the server to become ready first so callers don't need to
+    /// hand-roll reconnection logic around [`PostgreSQL::start`].
+    pub async fn connect_pool(&self, database_name: &str) -> Result<PgPool> {
+        self.connect_pool_with(database_name, &PoolOptions::default())
+            .await
+    }
+
+    /// Like [`PostgreSQL::connect_pool`], with custom [`PoolOptions`] tuning.
+    ///
+    /// This waits for the server to accept TCP connections (per
+    /// [`PostgreSQL::wait_until_ready`]), then connects the pool itself, retrying
+    /// transient connection failures with the same backoff so callers get a
+    /// ready-to-use pool in one call without hand-rolling reconnection logic around
+    /// [`PostgreSQL::start`]/[`PostgreSQL::stop`].
+    pub async fn connect_pool_with(&self, database_name: &str, options: &PoolOptions) -> Result<PgPool> {
+        let wait_options = WaitUntilReady::default();
+        self.wait_until_ready_with(&wait_options).await?;
+
+        let settings = self.settings();
+        let database_url = settings.url(database_name);
+        let start = Instant::now();
+        let mut interval = wait_options.initial_interval;
+
+        loop {
+            match settings.pool_options(options).connect(database_url.as_str()).await {
+                Ok(pool) => return Ok(pool),
+                Err(error) if is_transient(&error) && start.elapsed() < wait_options.max_elapsed_time => {
+                    tokio::time::sleep(interval).await;
+                    interval = wait_options.next_interval(interval);
+                }
+                Err(error) => return Err(Error::Pool(error)),
+            }
+        }
+    }
+}
+
+/// Returns `true` if `error` is a transient I/O condition during connection setup
+/// (mirroring [`crate::readiness::is_transient`]) that's worth retrying during pool
+/// warm-up, as opposed to a permanent failure such as bad credentials.
+fn is_transient(error: &sqlx::Error) -> bool {
+    matches!(error, sqlx::Error::Io(io_error) if crate::readiness::is_transient(io_error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_options() {
+        let options = PoolOptions::default();
+
+        assert_eq!(10, options.max_connections);
+        assert_eq!(0, options.min_connections);
+        assert_eq!(Duration::from_secs(30), options.acquire_timeout);
+        assert_eq!(Some(Duration::from_secs(600)), options.idle_timeout);
+        assert!(options.test_before_acquire);
+    }
+}
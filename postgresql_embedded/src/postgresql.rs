@@ -0,0 +1,86 @@
+//! The managed PostgreSQL instance itself.
+
+use crate::error::{Error, Result};
+use crate::readiness::WaitUntilReady;
+use crate::settings::Settings;
+use std::process::Stdio;
+use tokio::process::{Child, Command};
+use tokio::sync::Mutex;
+
+/// A PostgreSQL server managed by this process: spawned from [`Settings::binary_dir`]
+/// against [`Settings::data_dir`], and stopped when [`PostgreSQL::stop`] is called.
+pub struct PostgreSQL {
+    settings: Settings,
+    process: Mutex<Option<Child>>,
+}
+
+impl PostgreSQL {
+    /// Create a new instance configured with `settings`. Nothing is started until
+    /// [`PostgreSQL::start`] is called.
+    pub fn new(settings: Settings) -> Self {
+        Self {
+            settings,
+            process: Mutex::new(None),
+        }
+    }
+
+    /// The settings this instance was configured with.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Spawn the `postgres` postmaster process.
+    ///
+    /// This returns as soon as the process has been spawned; the server may not yet
+    /// be accepting connections. If [`Settings::wait_for_ready`] is set, this also
+    /// waits for the server to become ready (per [`PostgreSQL::wait_until_ready`])
+    /// before returning, so callers don't need to race a connect against startup
+    /// themselves. If that wait times out or hits a permanent error, the spawned
+    /// process is killed before the error is returned, so a caller that drops this
+    /// instance on error (e.g. `postgresql.start().await?`) doesn't leak a running
+    /// postmaster.
+    pub async fn start(&mut self) -> Result<()> {
+        let settings = &self.settings;
+        let program = settings.binary_dir.join("postgres");
+
+        let mut child = Command::new(program)
+            .arg("-D")
+            .arg(&settings.data_dir)
+            .arg("-h")
+            .arg(&settings.host)
+            .arg("-p")
+            .arg(settings.port.to_string())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(Error::Io)?;
+
+        if settings.wait_for_ready {
+            if let Err(error) = self.wait_until_ready_with(&WaitUntilReady::default()).await {
+                let _ = child.kill().await;
+                return Err(error);
+            }
+        }
+
+        *self.process.lock().await = Some(child);
+
+        Ok(())
+    }
+
+    /// Stop the running server, if any.
+    ///
+    /// Takes `&self` (over an internal `Mutex`) rather than `&mut self`, so an
+    /// instance shared via `Arc<PostgreSQL>` - for example with a
+    /// [`crate::MaintenanceScheduler`] holding its own clone - can still be stopped
+    /// by a caller holding another clone. Without this, `Arc::get_mut` would never
+    /// return `Some` while the scheduler's clone is alive, and there would be no
+    /// supported way to ever call `stop()` on an instance it was watching.
+    pub async fn stop(&self) -> Result<()> {
+        if let Some(mut process) = self.process.lock().await.take() {
+            process.kill().await.map_err(Error::Io)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+use crate::error::{Error, Result};
+use crate::postgresql::PostgreSQL;
+use std::io;
+use std::time::{Duration, Instant};
+use tokio::net::TcpStream;
+
+/// Options controlling how [`PostgreSQL::wait_until_ready`] polls for a ready server.
+///
+/// The backoff starts at `initial_interval` and is multiplied by `factor` after each
+/// failed attempt, capped at `max_interval`, until `max_elapsed_time` has passed.
+#[derive(Clone, Debug)]
+pub struct WaitUntilReady {
+    pub initial_interval: Duration,
+    pub factor: f64,
+    pub max_interval: Duration,
+    pub max_elapsed_time: Duration,
+}
+
+impl Default for WaitUntilReady {
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_millis(50),
+            factor: 1.5,
+            max_interval: Duration::from_secs(1),
+            max_elapsed_time: Duration::from_secs(30),
+        }
+    }
+}
+
+impl WaitUntilReady {
+    /// Create a new [`WaitUntilReady`] with the default backoff settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the initial interval between connection attempts.
+    pub fn initial_interval(mut self, initial_interval: Duration) -> Self {
+        self.initial_interval = initial_interval;
+        self
+    }
+
+    /// Set the multiplier applied to the interval after each failed attempt.
+    pub fn factor(mut self, factor: f64) -> Self {
+        self.factor = factor;
+        self
+    }
+
+    /// Set the maximum interval between connection attempts.
+    pub fn max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Set the maximum total time to wait before giving up.
+    pub fn max_elapsed_time(mut self, max_elapsed_time: Duration) -> Self {
+        self.max_elapsed_time = max_elapsed_time;
+        self
+    }
+
+    /// Compute the next interval after a failed attempt, capped at `max_interval`.
+    pub(crate) fn next_interval(&self, current: Duration) -> Duration {
+        let next = current.as_secs_f64() * self.factor;
+        Duration::from_secs_f64(next).min(self.max_interval)
+    }
+}
+
+/// Returns `true` if `error` represents a transient condition (the server is not yet
+/// accepting connections) that is worth retrying, as opposed to a permanent failure
+/// such as an authentication error that should be surfaced immediately.
+pub(crate) fn is_transient(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::ConnectionRefused | io::ErrorKind::ConnectionReset | io::ErrorKind::ConnectionAborted
+    )
+}
+
+impl PostgreSQL {
+    /// Wait until the server is accepting connections, using the default backoff.
+    ///
+    /// `start()` returns as soon as the postmaster process has been spawned; the
+    /// server may not yet be ready to accept connections. This polls a TCP connect
+    /// to the configured host/port with exponential backoff until it succeeds or
+    /// the default `max_elapsed_time` elapses, in which case [`Error::Timeout`] is
+    /// returned. Non-transient errors (e.g. the host name cannot be resolved) are
+    /// returned immediately. Any listener that accepts the TCP connection counts as
+    /// ready.
+    pub async fn wait_until_ready(&self) -> Result<()> {
+        self.wait_until_ready_with(&WaitUntilReady::default()).await
+    }
+
+    /// Like [`PostgreSQL::wait_until_ready`], with custom backoff [`WaitUntilReady`] options.
+    pub async fn wait_until_ready_with(&self, options: &WaitUntilReady) -> Result<()> {
+        let settings = self.settings();
+        let host = settings.host.clone();
+        let port = settings.port;
+        let start = Instant::now();
+        let mut interval = options.initial_interval;
+
+        loop {
+            let address = (host.as_str(), port);
+            let attempt = tokio::time::timeout(interval, TcpStream::connect(address)).await;
+            let is_ready = match attempt {
+                Ok(Ok(_)) => true,
+                Ok(Err(error)) if is_transient(&error) => false,
+                Ok(Err(error)) => return Err(Error::Io(error)),
+                Err(_elapsed) => false,
+            };
+
+            if is_ready {
+                return Ok(());
+            }
+
+            if start.elapsed() >= options.max_elapsed_time {
+                return Err(Error::Timeout(format!(
+                    "timed out after {:?} waiting for PostgreSQL to become ready on {host}:{port}",
+                    options.max_elapsed_time
+                )));
+            }
+            tokio::time::sleep(interval).await;
+            interval = options.next_interval(interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_options() {
+        let options = WaitUntilReady::default();
+
+        assert_eq!(Duration::from_millis(50), options.initial_interval);
+        assert_eq!(1.5, options.factor);
+        assert_eq!(Duration::from_secs(1), options.max_interval);
+        assert_eq!(Duration::from_secs(30), options.max_elapsed_time);
+    }
+
+    #[test]
+    fn test_next_interval_backs_off_and_caps() {
+        let options = WaitUntilReady::new()
+            .initial_interval(Duration::from_millis(100))
+            .factor(2.0)
+            .max_interval(Duration::from_millis(350));
+
+        let first = options.next_interval(options.initial_interval);
+        assert_eq!(Duration::from_millis(200), first);
+
+        let second = options.next_interval(first);
+        assert_eq!(Duration::from_millis(350), second);
+    }
+
+    #[test]
+    fn test_is_transient() {
+        assert!(is_transient(&io::Error::from(io::ErrorKind::ConnectionRefused)));
+        assert!(is_transient(&io::Error::from(io::ErrorKind::ConnectionReset)));
+        assert!(is_transient(&io::Error::from(io::ErrorKind::ConnectionAborted)));
+        assert!(!is_transient(&io::Error::from(io::ErrorKind::PermissionDenied)));
+        assert!(!is_transient(&io::Error::other("auth failed")));
+    }
+}